@@ -0,0 +1,77 @@
+use crate::table::{Output, Table};
+
+/// A boolean expression over the variables `0..N`, where `Term(i)` refers to
+/// input bit `i` of a [`Table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bool {
+    True,
+    False,
+    Term(usize),
+    Not(Box<Bool>),
+    And(Vec<Bool>),
+    Or(Vec<Bool>),
+}
+
+impl Bool {
+    /// Evaluates the expression for an assignment packed into `input`, where
+    /// bit `i` of `input` is the value of `Term(i)`.
+    pub fn eval(&self, input: usize) -> bool {
+        match self {
+            Bool::True => true,
+            Bool::False => false,
+            Bool::Term(var) => (input >> var) % 2 == 1,
+            Bool::Not(inner) => !inner.eval(input),
+            Bool::And(terms) => terms.iter().all(|term| term.eval(input)),
+            Bool::Or(terms) => terms.iter().any(|term| term.eval(input)),
+        }
+    }
+
+    /// Builds the truth table of this expression over all `2^N` assignments.
+    pub fn to_table<const N: usize>(&self) -> Table<N>
+    where
+        [(); 1 << N]: Sized,
+    {
+        Table {
+            outputs: (0..1 << N)
+                .map(|input| {
+                    if self.eval(input) {
+                        Output::One
+                    } else {
+                        Output::Zero
+                    }
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_table_matches_and() {
+        let and_a_b = Bool::And(vec![Bool::Term(0), Bool::Term(1)]);
+
+        let table = and_a_b.to_table::<2>();
+
+        assert_eq!(
+            table.outputs,
+            [Output::Zero, Output::Zero, Output::Zero, Output::One]
+        );
+    }
+
+    #[test]
+    fn minimize_to_expr_round_trips() {
+        let not_a_or_b = Bool::Or(vec![Bool::Not(Box::new(Bool::Term(0))), Bool::Term(1)]);
+
+        let table = Table::<2>::from_expr(&not_a_or_b);
+        let minimized = table.minimize_to_expr();
+
+        for input in 0..4 {
+            assert_eq!(not_a_or_b.eval(input), minimized.eval(input));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+pub mod expr;
+pub mod multi;
+pub mod pla;
+pub mod table;
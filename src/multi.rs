@@ -0,0 +1,297 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use crate::table::{cover, merge_round, Mergeable, Output};
+
+/// A prime implicant candidate shared across a subset of a [`MultiTable`]'s
+/// output functions, tracked by `tags` (the functions it is still valid for)
+/// and `constituents` (the `(function, minterm)` pairs it covers).
+#[derive(Clone, Eq)]
+struct TaggedImplicant<const N: usize> {
+    values: [Output; N],
+    tags: BTreeSet<usize>,
+    constituents: HashSet<(usize, usize)>,
+}
+
+impl<const N: usize> PartialEq for TaggedImplicant<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values && self.tags == other.tags
+    }
+}
+
+impl<const N: usize> Hash for TaggedImplicant<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values.hash(state);
+        self.tags.hash(state);
+    }
+}
+
+impl<const N: usize> TaggedImplicant<N> {
+    fn from_table_idx<const M: usize>(outputs: &[[Output; 1 << N]; M], idx: usize) -> Option<Self>
+    where
+        [(); 1 << N]: Sized,
+    {
+        let tags = (0..M)
+            .filter(|&function| !matches!(outputs[function][idx], Output::Zero))
+            .collect::<BTreeSet<_>>();
+
+        if tags.is_empty() {
+            return None;
+        }
+
+        let constituents = tags
+            .iter()
+            .filter(|&&function| matches!(outputs[function][idx], Output::One))
+            .map(|&function| (function, idx))
+            .collect();
+
+        let values = (0..N)
+            .map(|var| {
+                if (idx >> var) % 2 == 1 {
+                    Output::One
+                } else {
+                    Output::Zero
+                }
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        Some(Self {
+            values,
+            tags,
+            constituents,
+        })
+    }
+
+    /// This implicant with its tags (and the constituents they no longer
+    /// cover) narrowed down to `tags`.
+    fn restricted_to(&self, tags: BTreeSet<usize>) -> Self {
+        let constituents = self
+            .constituents
+            .iter()
+            .filter(|(function, _)| tags.contains(function))
+            .copied()
+            .collect();
+
+        Self {
+            values: self.values,
+            tags,
+            constituents,
+        }
+    }
+}
+
+impl<const N: usize> Mergeable for TaggedImplicant<N> {
+    type Mask = u128;
+
+    /// Mirrors `Implicant::merge_key`: the exact set of `DontCare` positions,
+    /// plus the number of positions fixed at `One`.
+    fn merge_key(&self) -> (u128, u32) {
+        let mut dont_care_mask = 0u128;
+        let mut one_count = 0u32;
+
+        for (var, value) in self.values.iter().enumerate() {
+            match value {
+                Output::DontCare => dont_care_mask |= 1 << var,
+                Output::One => one_count += 1,
+                Output::Zero => {}
+            }
+        }
+
+        (dont_care_mask, one_count)
+    }
+
+    fn try_merge(&self, other: &Self) -> Option<Self> {
+        let tags = self
+            .tags
+            .intersection(&other.tags)
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        if tags.is_empty() {
+            return None;
+        }
+
+        let mut diff_idx = None;
+
+        for idx in 0..N {
+            match (self.values[idx], other.values[idx]) {
+                (a, b) if a == b => continue,
+                (Output::Zero, Output::One) | (Output::One, Output::Zero) if diff_idx.is_none() => {
+                    diff_idx = Some(idx)
+                }
+                _ => return None,
+            }
+        }
+
+        diff_idx.map(|diff_idx| {
+            let mut values = self.values;
+            values[diff_idx] = Output::DontCare;
+
+            let constituents = self
+                .constituents
+                .iter()
+                .chain(other.constituents.iter())
+                .filter(|(function, _)| tags.contains(function))
+                .copied()
+                .collect();
+
+            Self {
+                values,
+                tags,
+                constituents,
+            }
+        })
+    }
+}
+
+/// `M` boolean functions of `N` shared inputs, minimized together so that
+/// prime implicants may be reused between outputs (e.g. the segments of a
+/// 7-segment decoder).
+pub struct MultiTable<const N: usize, const M: usize>
+where
+    [(); 1 << N]: Sized,
+{
+    pub outputs: [[Output; 1 << N]; M],
+}
+
+impl<const N: usize, const M: usize> MultiTable<N, M>
+where
+    [(); 1 << N]: Sized,
+{
+    const ENTRIES: usize = 1 << N;
+
+    fn minterms(&self) -> HashSet<(usize, usize)> {
+        (0..M)
+            .flat_map(|function| {
+                (0..Self::ENTRIES).filter_map(move |idx| {
+                    matches!(self.outputs[function][idx], Output::One).then_some((function, idx))
+                })
+            })
+            .collect()
+    }
+
+    fn prime_implicants(&self) -> Vec<TaggedImplicant<N>> {
+        let mut implicants = (0..Self::ENTRIES)
+            .filter_map(|idx| TaggedImplicant::from_table_idx(&self.outputs, idx))
+            .collect::<Vec<_>>();
+        let mut prime_implicants = Vec::new();
+
+        loop {
+            // A cube's tags can resolve independently: it may legally merge
+            // under one tag while staying the sole implicant for another, so
+            // track which tags actually found a merge partner rather than a
+            // single merged/unmerged bit per cube.
+            let merges = merge_round(&implicants);
+
+            let mut merged_tags = vec![BTreeSet::new(); implicants.len()];
+            let mut next_implicants = HashSet::with_capacity(implicants.capacity());
+
+            for (first_idx, second_idx, merge) in merges {
+                merged_tags[first_idx].extend(merge.tags.iter().copied());
+                merged_tags[second_idx].extend(merge.tags.iter().copied());
+
+                next_implicants.insert(merge);
+            }
+
+            for (idx, implicant) in implicants.iter().enumerate() {
+                let unresolved = implicant
+                    .tags
+                    .difference(&merged_tags[idx])
+                    .copied()
+                    .collect::<BTreeSet<_>>();
+
+                if !unresolved.is_empty() {
+                    prime_implicants.push(implicant.restricted_to(unresolved));
+                }
+            }
+
+            if next_implicants.is_empty() {
+                break;
+            }
+
+            implicants = next_implicants.into_iter().collect();
+        }
+
+        prime_implicants
+    }
+
+    /// Runs Quine-McCluskey over the tagged product space shared between all
+    /// `M` outputs, then selects a shared pool of implicants that covers
+    /// every `(function, minterm)` pair. Each cube is paired with the subset
+    /// of outputs it's tagged for: a shared implicant isn't necessarily safe
+    /// to wire into every one of the `M` functions, only the ones in its tag
+    /// set.
+    pub fn minimize(&self) -> Vec<(BTreeSet<usize>, Vec<Output>)> {
+        let mut primes = self.prime_implicants();
+
+        let sets = primes
+            .iter()
+            .map(|prime| &prime.constituents)
+            .collect::<Vec<_>>();
+        let universe = self.minterms();
+        let weights = vec![1.0; sets.len()];
+
+        cover(&universe, &sets, &weights)
+            .iter()
+            .rev()
+            .map(|&idx| {
+                let prime = primes.remove(idx);
+                (prime.tags, prime.values.to_vec())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_primes_across_outputs_without_losing_coverage() {
+        // f0 = AND(a, b), f1 = OR(a, b): f0's only minterm must stay covered
+        // even though it merges away under f1's tag.
+        let table = MultiTable::<2, 2> {
+            outputs: [
+                [Output::Zero, Output::Zero, Output::Zero, Output::One],
+                [Output::Zero, Output::One, Output::One, Output::One],
+            ],
+        };
+
+        let primes = table.prime_implicants();
+        let universe = table.minterms();
+
+        for pair in &universe {
+            assert!(
+                primes.iter().any(|prime| prime.constituents.contains(pair)),
+                "no prime implicant covers {pair:?}"
+            );
+        }
+
+        let cover = table.minimize();
+        assert_eq!(cover.len(), 3);
+
+        // Every (function, minterm) pair must be wired to a returned cube
+        // that's actually tagged for that function.
+        for (function, minterm) in &universe {
+            assert!(
+                cover.iter().any(|(tags, values)| {
+                    tags.contains(function)
+                        && (0..2).all(|var| {
+                            let bit = if (minterm >> var) % 2 == 1 {
+                                Output::One
+                            } else {
+                                Output::Zero
+                            };
+
+                            matches!(values[var], Output::DontCare) || values[var] == bit
+                        })
+                }),
+                "no tagged cube wires (function={function}, minterm={minterm})"
+            );
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    collections::HashSet,
+    fmt::Write as _,
+    hash::{Hash, Hasher},
+};
+
+use crate::table::{cover, merge_round, Mergeable, Output};
+
+/// A single prime-implicant candidate over a runtime-known number of
+/// inputs, the `Vec`-backed counterpart of `table::Implicant` for tables
+/// whose size isn't known at compile time.
+#[derive(Clone, Eq)]
+struct DynImplicant {
+    values: Vec<Output>,
+    constituents: HashSet<usize>,
+}
+
+impl PartialEq for DynImplicant {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl Hash for DynImplicant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values.hash(state);
+    }
+}
+
+impl DynImplicant {
+    fn from_row(values: Vec<Output>, idx: usize, is_minterm: bool) -> Self {
+        Self {
+            values,
+            constituents: if is_minterm {
+                HashSet::from([idx])
+            } else {
+                HashSet::new()
+            },
+        }
+    }
+
+}
+
+impl Mergeable for DynImplicant {
+    type Mask = Vec<bool>;
+
+    /// Unlike `table::Implicant::merge_key`, the `DontCare` mask can't be
+    /// packed into a fixed-width integer: `DynTable` exists precisely to
+    /// ingest PLA files whose input count isn't bounded at compile time.
+    fn merge_key(&self) -> (Vec<bool>, u32) {
+        let mut dont_care_mask = Vec::with_capacity(self.values.len());
+        let mut one_count = 0u32;
+
+        for value in &self.values {
+            match value {
+                Output::DontCare => dont_care_mask.push(true),
+                Output::One => {
+                    dont_care_mask.push(false);
+                    one_count += 1;
+                }
+                Output::Zero => dont_care_mask.push(false),
+            }
+        }
+
+        (dont_care_mask, one_count)
+    }
+
+    fn try_merge(&self, other: &Self) -> Option<Self> {
+        let mut diff_idx = None;
+
+        for idx in 0..self.values.len() {
+            match (self.values[idx], other.values[idx]) {
+                (a, b) if a == b => continue,
+                (Output::Zero, Output::One) | (Output::One, Output::Zero) if diff_idx.is_none() => {
+                    diff_idx = Some(idx)
+                }
+                _ => return None,
+            }
+        }
+
+        diff_idx.map(|diff_idx| {
+            let mut result = self.clone();
+
+            result.constituents.extend(other.constituents.iter());
+            result.values[diff_idx] = Output::DontCare;
+
+            result
+        })
+    }
+}
+
+/// A runtime-sized single-output truth table: a row per assignment cube
+/// (which may itself contain `DontCare` inputs), in the style of a Berkeley
+/// PLA file. Unlike `table::Table<N>`, `inputs` doesn't need to be known at
+/// compile time, so this can ingest logic descriptions read from disk.
+pub struct DynTable {
+    pub inputs: usize,
+    pub rows: Vec<(Vec<Output>, Output)>,
+}
+
+impl DynTable {
+    fn minterms(&self) -> HashSet<usize> {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, (_, output))| matches!(output, Output::One).then_some(idx))
+            .collect()
+    }
+
+    fn prime_implicants(&self) -> Vec<DynImplicant> {
+        let mut implicants = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, output))| !matches!(output, Output::Zero))
+            .map(|(idx, (values, output))| {
+                DynImplicant::from_row(values.clone(), idx, matches!(output, Output::One))
+            })
+            .collect::<Vec<_>>();
+        let mut prime_implicants = Vec::new();
+
+        loop {
+            let merges = merge_round(&implicants);
+
+            let mut merged = vec![false; implicants.len()];
+            let mut next_implicants = HashSet::with_capacity(implicants.capacity());
+
+            for (first_idx, second_idx, merge) in merges {
+                merged[first_idx] = true;
+                merged[second_idx] = true;
+
+                next_implicants.insert(merge);
+            }
+
+            for (idx, implicant) in implicants.iter().enumerate() {
+                if !merged[idx] {
+                    prime_implicants.push(implicant.clone());
+                }
+            }
+
+            if next_implicants.is_empty() {
+                break;
+            }
+
+            implicants = next_implicants.into_iter().collect();
+        }
+
+        prime_implicants
+    }
+
+    /// Runs the same Quine-McCluskey plus set-cover pipeline as
+    /// `table::Table::minimize`, over `Vec`-backed implicants, returning
+    /// one cube per chosen prime implicant.
+    pub fn minimize(&self) -> Vec<Vec<Output>> {
+        let mut primes = self.prime_implicants();
+
+        let sets = primes
+            .iter()
+            .map(|prime| &prime.constituents)
+            .collect::<Vec<_>>();
+        let universe = self.minterms();
+        let weights = vec![1.0; sets.len()];
+
+        cover(&universe, &sets, &weights)
+            .iter()
+            .rev()
+            .map(|&idx| primes.remove(idx).values)
+            .collect()
+    }
+
+    /// Minimizes this table and renders the result as a PLA cube list, one
+    /// `.p` row per chosen prime implicant.
+    pub fn minimize_to_pla(&self) -> String {
+        Self {
+            inputs: self.inputs,
+            rows: self
+                .minimize()
+                .into_iter()
+                .map(|cube| (cube, Output::One))
+                .collect(),
+        }
+        .to_pla()
+    }
+
+    /// Parses the standard `.i`/`.o`/`.p`/`.e` PLA directives and a
+    /// cube-per-line body (`010- 1`, where `-` marks a `DontCare` input).
+    /// Only single-output (`.o 1`) files are supported.
+    pub fn from_pla(text: &str) -> Self {
+        let mut inputs = 0;
+        let mut rows = Vec::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line == ".e" {
+                break;
+            }
+
+            if let Some(value) = line.strip_prefix(".i ") {
+                inputs = value.trim().parse().expect("`.i` directive takes an integer");
+            } else if line.starts_with('.') {
+                continue;
+            } else {
+                let (cube, output) = line
+                    .split_once(char::is_whitespace)
+                    .expect("cube row must have a cube and an output separated by whitespace");
+
+                let values = cube
+                    .trim()
+                    .chars()
+                    .map(|char| match char {
+                        '0' => Output::Zero,
+                        '1' => Output::One,
+                        '-' => Output::DontCare,
+                        _ => panic!("invalid PLA cube character: {char}"),
+                    })
+                    .collect();
+
+                let output = match output.trim() {
+                    "0" => Output::Zero,
+                    "1" => Output::One,
+                    "-" => Output::DontCare,
+                    other => panic!("invalid PLA output character: {other}"),
+                };
+
+                rows.push((values, output));
+            }
+        }
+
+        Self { inputs, rows }
+    }
+
+    /// Renders this table back into the standard PLA text format.
+    pub fn to_pla(&self) -> String {
+        let mut text = String::new();
+
+        writeln!(text, ".i {}", self.inputs).unwrap();
+        writeln!(text, ".o 1").unwrap();
+        writeln!(text, ".p {}", self.rows.len()).unwrap();
+
+        for (values, output) in &self.rows {
+            for value in values {
+                write!(text, "{value}").unwrap();
+            }
+
+            writeln!(text, " {output}").unwrap();
+        }
+
+        writeln!(text, ".e").unwrap();
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_pla_text() {
+        let pla = ".i 2\n.o 1\n.p 3\n00 0\n01 1\n1- 1\n.e\n";
+
+        let table = DynTable::from_pla(pla);
+
+        assert_eq!(table.inputs, 2);
+        assert_eq!(
+            table.rows,
+            vec![
+                (vec![Output::Zero, Output::Zero], Output::Zero),
+                (vec![Output::Zero, Output::One], Output::One),
+                (vec![Output::One, Output::DontCare], Output::One),
+            ]
+        );
+        assert_eq!(DynTable::from_pla(&table.to_pla()).rows, table.rows);
+    }
+
+    #[test]
+    fn minimize_to_pla_is_equivalent_to_original() {
+        // f(a, b) = a OR b
+        let table = DynTable {
+            inputs: 2,
+            rows: vec![
+                (vec![Output::Zero, Output::Zero], Output::Zero),
+                (vec![Output::Zero, Output::One], Output::One),
+                (vec![Output::One, Output::Zero], Output::One),
+                (vec![Output::One, Output::One], Output::One),
+            ],
+        };
+
+        let minimized = DynTable::from_pla(&table.minimize_to_pla());
+
+        for a in [Output::Zero, Output::One] {
+            for b in [Output::Zero, Output::One] {
+                let is_minterm = (a, b) != (Output::Zero, Output::Zero);
+
+                let matches = minimized.rows.iter().any(|(values, output)| {
+                    matches!(output, Output::One)
+                        && (values[0] == Output::DontCare || values[0] == a)
+                        && (values[1] == Output::DontCare || values[1] == b)
+                });
+
+                assert_eq!(is_minterm, matches);
+            }
+        }
+    }
+}
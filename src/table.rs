@@ -1,5 +1,5 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fmt::{self, Display, Write},
     hash::Hash,
     iter::Sum,
@@ -8,6 +8,101 @@ use std::{
 
 use good_lp::{coin_cbc, variable, Expression, ProblemVariables, Solution, SolverModel};
 
+use crate::expr::Bool;
+
+/// Solves a minimum-weight set-cover problem over `universe` via integer
+/// linear programming, returning the indices of `sets` to include. `weights`
+/// must have one entry per set; pass all `1.0` to minimize the number of
+/// sets chosen. Shared by [`Table::minimize_with`] and
+/// [`crate::multi::MultiTable::minimize`].
+pub(crate) fn cover<T: Eq + Hash>(
+    universe: &HashSet<T>,
+    sets: &[&HashSet<T>],
+    weights: &[f64],
+) -> Vec<usize> {
+    let mut problem = ProblemVariables::new();
+
+    let include_vars = problem.add_vector(variable().min(0).max(1).integer(), sets.len());
+
+    let mut model = problem
+        .minimise(Expression::sum(
+            include_vars.iter().zip(weights).map(|(var, weight)| *weight * *var),
+        ))
+        .using(coin_cbc);
+
+    model.set_parameter("loglevel", "0");
+
+    for item in universe {
+        model = model.with(
+            Expression::sum(
+                sets.iter()
+                    .zip(&include_vars)
+                    .filter_map(|(set, include_var)| set.contains(item).then_some(include_var)),
+            )
+            .geq(1),
+        );
+    }
+
+    let solution = model.solve().unwrap();
+
+    include_vars
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, include_var)| (solution.value(*include_var) > 0.0).then_some(idx))
+        .collect()
+}
+
+/// An implicant-like cube that can be grouped into merge-compatibility
+/// buckets and combined with an adjacent one, so [`merge_round`] can drive
+/// Quine-McCluskey's merge step once for [`Implicant`], [`crate::multi::TaggedImplicant`],
+/// and [`crate::pla::DynImplicant`] instead of three divergent copies.
+pub(crate) trait Mergeable: Sized {
+    /// The don't-care mask, packed however is cheapest for this implicant's
+    /// backing storage (a fixed-width bitmask when `N` is known at compile
+    /// time, a `Vec<bool>` when it isn't).
+    type Mask: Eq + Hash + Clone;
+
+    /// The key [`merge_round`] buckets implicants by: the mask, plus the
+    /// number of positions fixed at `One`. Two implicants merge only when
+    /// their masks match and their one-counts differ by exactly one, so
+    /// implicants sharing a whole key never merge with each other.
+    fn merge_key(&self) -> (Self::Mask, u32);
+
+    fn try_merge(&self, other: &Self) -> Option<Self>;
+}
+
+/// Runs one round of Quine-McCluskey merging: buckets `implicants` by
+/// [`Mergeable::merge_key`] and attempts every pair across adjacent
+/// one-count buckets, instead of every pair in the whole slice. Returns
+/// the index of each side of every successful merge alongside the result,
+/// so callers can decide for themselves how a merge retires an implicant
+/// (a single `merged` flag for single-output tables, a per-tag set for
+/// [`crate::multi::MultiTable`]).
+pub(crate) fn merge_round<I: Mergeable>(implicants: &[I]) -> Vec<(usize, usize, I)> {
+    let mut buckets: HashMap<(I::Mask, u32), Vec<usize>> = HashMap::new();
+    for (idx, implicant) in implicants.iter().enumerate() {
+        buckets.entry(implicant.merge_key()).or_default().push(idx);
+    }
+
+    let mut merges = Vec::new();
+
+    for ((mask, one_count), bucket) in &buckets {
+        let Some(adjacent_bucket) = buckets.get(&(mask.clone(), one_count + 1)) else {
+            continue;
+        };
+
+        for &first_idx in bucket {
+            for &second_idx in adjacent_bucket {
+                if let Some(merge) = implicants[first_idx].try_merge(&implicants[second_idx]) {
+                    merges.push((first_idx, second_idx, merge));
+                }
+            }
+        }
+    }
+
+    merges
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Output {
     Zero,
@@ -15,6 +110,16 @@ pub enum Output {
     DontCare,
 }
 
+/// The objective minimized by [`Table::minimize_with`]'s set-cover ILP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cost {
+    /// Minimize the number of prime implicants in the cover.
+    TermCount,
+    /// Minimize the total literal count across the cover's product terms,
+    /// the standard gate-area proxy in logic synthesis.
+    LiteralCount,
+}
+
 impl Display for Output {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_char(match self {
@@ -60,6 +165,15 @@ impl<const N: usize> Implicant<N> {
         self.constituents.len()
     }
 
+    /// The number of non-`DontCare` positions, i.e. the literal count of the
+    /// product term this implicant renders as.
+    fn literal_count(&self) -> usize {
+        self.values
+            .iter()
+            .filter(|value| !matches!(value, Output::DontCare))
+            .count()
+    }
+
     fn from_table_idx(idx: usize, is_minterm: bool) -> Self {
         Self {
             values: (0..N)
@@ -81,6 +195,69 @@ impl<const N: usize> Implicant<N> {
         }
     }
 
+    /// Turns this implicant into a product term: a literal per non-`DontCare`
+    /// position, negated for positions fixed at `Zero`.
+    pub fn to_bool(&self) -> Bool {
+        let literals = self
+            .values
+            .iter()
+            .enumerate()
+            .filter_map(|(var, value)| match value {
+                Output::One => Some(Bool::Term(var)),
+                Output::Zero => Some(Bool::Not(Box::new(Bool::Term(var)))),
+                Output::DontCare => None,
+            })
+            .collect::<Vec<_>>();
+
+        if literals.is_empty() {
+            Bool::True
+        } else {
+            Bool::And(literals)
+        }
+    }
+
+    /// Whether this implicant's covered assignments are a superset of
+    /// `other`'s, i.e. `other` is redundant next to this one. True prime
+    /// implicants never absorb one another.
+    pub fn absorbs(&self, other: &Self) -> bool {
+        self != other
+            && (0..N).all(|idx| {
+                matches!(self.values[idx], Output::DontCare) || self.values[idx] == other.values[idx]
+            })
+    }
+
+    /// Whether `input`'s bits match every non-`DontCare` position.
+    fn matches(&self, input: usize) -> bool {
+        (0..N).all(|var| {
+            let bit = if (input >> var) % 2 == 1 {
+                Output::One
+            } else {
+                Output::Zero
+            };
+
+            matches!(self.values[var], Output::DontCare) || self.values[var] == bit
+        })
+    }
+}
+
+impl<const N: usize> Mergeable for Implicant<N> {
+    type Mask = u128;
+
+    fn merge_key(&self) -> (u128, u32) {
+        let mut dont_care_mask = 0u128;
+        let mut one_count = 0u32;
+
+        for (var, value) in self.values.iter().enumerate() {
+            match value {
+                Output::DontCare => dont_care_mask |= 1 << var,
+                Output::One => one_count += 1,
+                Output::Zero => {}
+            }
+        }
+
+        (dont_care_mask, one_count)
+    }
+
     fn try_merge(&self, other: &Self) -> Option<Self> {
         let mut diff_idx = None;
 
@@ -118,6 +295,7 @@ impl<const N: usize> Display for Implicant<N> {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct Table<const N: usize>
 where
     [(); 1 << N]: Sized,
@@ -125,12 +303,32 @@ where
     pub outputs: [Output; 1 << N],
 }
 
+/// The result of [`Table::coverage_with`]: the prime implicants forced into
+/// the cover because they're the sole implicant over some minterm, plus
+/// whatever the set-cover ILP chose among the remaining (cyclic) residual.
+pub struct Coverage<const N: usize> {
+    pub essential: Vec<Implicant<N>>,
+    pub chosen: Vec<Implicant<N>>,
+}
+
+impl<const N: usize> Coverage<N> {
+    pub fn into_implicants(self) -> Vec<Implicant<N>> {
+        self.essential.into_iter().chain(self.chosen).collect()
+    }
+}
+
 impl<const N: usize> Table<N>
 where
     [(); 1 << N]: Sized,
 {
     const ENTRIES: usize = 1 << N;
 
+    /// Builds the truth table of a boolean expression over all `2^N`
+    /// assignments.
+    pub fn from_expr(expr: &Bool) -> Self {
+        expr.to_table()
+    }
+
     pub fn minterms(&self) -> HashSet<usize> {
         self.outputs
             .iter()
@@ -143,6 +341,16 @@ where
         matches!(self.outputs[idx], Output::One)
     }
 
+    /// ORs the selected implicants' match against `input`: true iff some
+    /// implicant in `minterms_selected` agrees with `input` on every
+    /// non-`DontCare` position. Used to check that a minimized cover is
+    /// equivalent to the original table.
+    pub fn evaluate(&self, minterms_selected: &[Implicant<N>], input: usize) -> bool {
+        minterms_selected
+            .iter()
+            .any(|implicant| implicant.matches(input))
+    }
+
     pub fn prime_implicants(&self) -> Vec<Implicant<N>> {
         let mut implicants = (0..Self::ENTRIES)
             .filter(|&idx| !matches!(self.outputs[idx], Output::Zero))
@@ -151,25 +359,21 @@ where
         let mut prime_implicants = Vec::new();
 
         loop {
+            let merges = merge_round(&implicants);
+
             let mut merged = vec![false; implicants.len()];
             let mut next_implicants = HashSet::with_capacity(implicants.capacity());
 
-            for first_idx in 0..implicants.len() {
-                let first = &implicants[first_idx];
-
-                for second_idx in first_idx..implicants.len() {
-                    let second = &implicants[second_idx];
+            for (first_idx, second_idx, merge) in merges {
+                merged[first_idx] = true;
+                merged[second_idx] = true;
 
-                    if let Some(merge) = first.try_merge(second) {
-                        next_implicants.insert(merge);
-
-                        merged[first_idx] = true;
-                        merged[second_idx] = true;
-                    }
-                }
+                next_implicants.insert(merge);
+            }
 
-                if !merged[first_idx] {
-                    prime_implicants.push(first.clone());
+            for (idx, implicant) in implicants.iter().enumerate() {
+                if !merged[idx] {
+                    prime_implicants.push(implicant.clone());
                 }
             }
 
@@ -180,53 +384,186 @@ where
             implicants = next_implicants.into_iter().collect();
         }
 
+        prime_implicants.sort_by_cached_key(Implicant::constituent_count);
+
         prime_implicants
     }
 
+    /// Minimizes the number of prime implicants in the cover.
     pub fn minimize(&self) -> Vec<Implicant<N>> {
-        fn cover<T: Eq + Hash>(universe: &HashSet<T>, sets: &[&HashSet<T>]) -> Vec<usize> {
-            let mut problem = ProblemVariables::new();
+        self.minimize_with(Cost::TermCount)
+    }
 
-            let include_vars = problem.add_vector(variable().min(0).max(1).integer(), sets.len());
+    /// Minimizes the cover's prime implicants under the given [`Cost`].
+    pub fn minimize_with(&self, cost: Cost) -> Vec<Implicant<N>> {
+        self.coverage_with(cost).into_implicants()
+    }
 
-            let mut model = problem
-                .minimise(Expression::sum(include_vars.iter()))
-                .using(coin_cbc);
+    /// Like [`Table::minimize_with`], but exposes which implicants were
+    /// forced essential (the sole cover of some minterm) separately from
+    /// the ones the set-cover ILP chose among the rest.
+    pub fn coverage_with(&self, cost: Cost) -> Coverage<N> {
+        let mut primes = self.prime_implicants();
+        let mut universe = self.minterms();
 
-            model.set_parameter("loglevel", "0");
+        let mut coverers = HashMap::with_capacity(universe.len());
+        for prime in &primes {
+            for &minterm in &prime.constituents {
+                *coverers.entry(minterm).or_insert(0usize) += 1;
+            }
+        }
 
-            for item in universe {
-                model = model.with(
-                    Expression::sum(sets.iter().zip(&include_vars).filter_map(
-                        |(set, include_var)| set.contains(item).then_some(include_var),
-                    ))
-                    .geq(1),
-                );
+        let essential_indices = primes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, prime)| {
+                prime
+                    .constituents
+                    .iter()
+                    .any(|minterm| coverers[minterm] == 1)
+                    .then_some(idx)
+            })
+            .collect::<HashSet<_>>();
+
+        let mut essential = Vec::new();
+        let mut idx = 0;
+        primes.retain(|prime| {
+            let is_essential = essential_indices.contains(&idx);
+            idx += 1;
+
+            if is_essential {
+                essential.push(prime.clone());
             }
 
-            let solution = model.solve().unwrap();
+            !is_essential
+        });
 
-            include_vars
+        for prime in &essential {
+            universe.retain(|minterm| !prime.constituents.contains(minterm));
+        }
+        primes.retain(|prime| prime.constituents.iter().any(|minterm| universe.contains(minterm)));
+
+        let chosen = if universe.is_empty() {
+            Vec::new()
+        } else {
+            let weights = primes
                 .iter()
-                .enumerate()
-                .filter_map(|(idx, include_var)| {
-                    (solution.value(*include_var) > 0.0).then_some(idx)
+                .map(|prime| match cost {
+                    Cost::TermCount => 1.0,
+                    Cost::LiteralCount => prime.literal_count() as f64,
                 })
+                .collect::<Vec<_>>();
+            let sets = primes
+                .iter()
+                .map(|prime| &prime.constituents)
+                .collect::<Vec<_>>();
+
+            cover(&universe, &sets, &weights)
+                .iter()
+                .rev()
+                .map(|&idx| primes.remove(idx))
                 .collect()
-        }
+        };
 
-        let mut primes = self.prime_implicants();
+        Coverage { essential, chosen }
+    }
 
-        let sets = primes
+    /// Minimizes this table and renders the result as a sum-of-products
+    /// [`Bool`] expression.
+    pub fn minimize_to_expr(&self) -> Bool {
+        let terms = self
+            .minimize()
             .iter()
-            .map(|prime| &prime.constituents)
+            .map(Implicant::to_bool)
             .collect::<Vec<_>>();
-        let universe = self.minterms();
 
-        cover(&universe, &sets)
-            .iter()
-            .rev()
-            .map(|&idx| primes.remove(idx))
-            .collect()
+        if terms.is_empty() {
+            Bool::False
+        } else {
+            Bool::Or(terms)
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<const N: usize> quickcheck::Arbitrary for Table<N>
+where
+    [(); 1 << N]: Sized,
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Table {
+            outputs: (0..Self::ENTRIES)
+                .map(|_| {
+                    *g.choose(&[Output::Zero, Output::One, Output::DontCare])
+                        .unwrap()
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_cost_never_exceeds_term_cost_on_literals() {
+        let table = Table::<3> {
+            outputs: [
+                Output::One,
+                Output::One,
+                Output::One,
+                Output::One,
+                Output::One,
+                Output::One,
+                Output::Zero,
+                Output::Zero,
+            ],
+        };
+
+        let by_terms = table.minimize_with(Cost::TermCount);
+        let by_literals = table.minimize_with(Cost::LiteralCount);
+
+        let total_literals = |cover: &[Implicant<3>]| -> usize {
+            cover.iter().map(Implicant::literal_count).sum()
+        };
+
+        assert!(total_literals(&by_literals) <= total_literals(&by_terms));
+
+        for input in 0..8 {
+            assert_eq!(table.is_minterm(input), table.evaluate(&by_literals, input));
+        }
+    }
+
+    #[test]
+    fn coverage_essentials_plus_chosen_match_minimize() {
+        let table = Table::<3> {
+            outputs: [
+                Output::One,
+                Output::Zero,
+                Output::One,
+                Output::One,
+                Output::Zero,
+                Output::One,
+                Output::One,
+                Output::One,
+            ],
+        };
+
+        let coverage = table.coverage_with(Cost::TermCount);
+        let essential_count = coverage.essential.len();
+        let chosen_count = coverage.chosen.len();
+
+        assert!(essential_count > 0);
+
+        let implicants = coverage.into_implicants();
+        assert_eq!(essential_count + chosen_count, implicants.len());
+        assert_eq!(table.minimize().len(), implicants.len());
+
+        for input in 0..8 {
+            assert_eq!(table.is_minterm(input), table.evaluate(&implicants, input));
+        }
     }
 }
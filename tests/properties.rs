@@ -0,0 +1,22 @@
+#![cfg(feature = "quickcheck")]
+
+use bigbrain::table::{Output, Table};
+
+quickcheck::quickcheck! {
+    fn minimize_matches_original(table: Table<4>) -> bool {
+        let minimized = table.minimize();
+
+        (0..16).all(|input| {
+            matches!(table.outputs[input], Output::DontCare)
+                || table.is_minterm(input) == table.evaluate(&minimized, input)
+        })
+    }
+
+    fn prime_implicants_dont_absorb_each_other(table: Table<4>) -> bool {
+        let primes = table.prime_implicants();
+
+        !primes
+            .iter()
+            .any(|a| primes.iter().any(|b| a.absorbs(b)))
+    }
+}